@@ -1,66 +1,34 @@
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
-use std::path::Path;
-
 use std::collections::HashMap;
 
 use nom::*;
 
-#[derive(Hash, Eq, PartialEq, Debug, Clone)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl Point {
-    fn new(x: usize, y: usize) -> Point {
-        Point { x: x, y: y }
-    }
-}
-
-fn get_input_lines(filename: &str) -> io::Result<Vec<String>> {
-    let path = Path::new(filename);
-    let file = File::open(&path)?;
-
-    BufReader::new(file).lines().collect()
-}
+use crate::geometry::Point;
+use crate::{Config, Output};
 
 named!(parse_point<&str, Point>,
     do_parse!(
-        x:  map_res!(take_until_and_consume!(", "), str::parse::<usize>) >>
-        y:  map_res!(nom::rest, str::parse::<usize>) >>
+        x:  map_res!(take_until_and_consume!(", "), str::parse::<i32>) >>
+        y:  map_res!(nom::rest, str::parse::<i32>) >>
         (Point::new(x, y))
     )
 );
 
-fn get_input_points(lines: &[String]) -> Vec<Point> {
-    lines
-        .iter()
+fn get_input_points(input: &str) -> Vec<Point> {
+    input
+        .lines()
         .map(|s| parse_point(s).unwrap())
         .map(|(_, p)| p)
         .collect()
 }
 
-fn abs_diff(from: usize, to: usize) -> usize {
-    if from > to {
-        from - to
-    } else {
-        to - from
-    }
-}
-
-fn distance(from: &Point, to: &Point) -> usize {
-    abs_diff(from.x, to.x) + abs_diff(from.y, to.y)
-}
-
 fn get_closest_point(from: &Point, points: &[Point]) -> Option<usize> {
-    let mut smallest_distance = std::usize::MAX;
+    let mut smallest_distance = std::i32::MAX;
     let mut smallest_distance_count = 0;
     let mut closest_point = std::usize::MAX;
 
     for i in 0..points.len() {
         let other = &points[i];
-        let dist = distance(from, other);
+        let dist = from.manhattan(*other);
 
         if dist < smallest_distance {
             smallest_distance = dist;
@@ -86,10 +54,10 @@ fn has_finite_area(owner: &Point, others: &[Point]) -> bool {
 }
 
 fn get_largest_finite_area(points: &[Point]) -> usize {
-    let min_x = points.iter().map(|p| p.x).min().unwrap().clone();
-    let min_y = points.iter().map(|p| p.y).min().unwrap().clone();
-    let max_x = points.iter().map(|p| p.x).max().unwrap().clone();
-    let max_y = points.iter().map(|p| p.y).max().unwrap().clone();
+    let min_x = points.iter().map(|p| p.x).min().unwrap();
+    let min_y = points.iter().map(|p| p.y).min().unwrap();
+    let max_x = points.iter().map(|p| p.x).max().unwrap();
+    let max_y = points.iter().map(|p| p.y).max().unwrap();
     let mut area_by_owner = HashMap::new();
 
     for x in min_x..=max_x {
@@ -114,21 +82,15 @@ fn get_largest_finite_area(points: &[Point]) -> usize {
     finite_areas[last].clone()
 }
 
-fn part1(points: &[Point]) {
-    let area = get_largest_finite_area(points);
-
-    println!("Part1: {:?}", area);
-}
-
-fn get_farness(location: &Point, coordinates: &[Point]) -> usize {
-    coordinates.iter().map(|c| distance(location, c)).sum()
+fn get_farness(location: &Point, coordinates: &[Point]) -> i32 {
+    coordinates.iter().map(|c| location.manhattan(*c)).sum()
 }
 
-fn get_safe_region_size(coordinates: &[Point], max_farness: usize) -> usize {
-    let min_x = coordinates.iter().map(|p| p.x).min().unwrap().clone();
-    let min_y = coordinates.iter().map(|p| p.y).min().unwrap().clone();
-    let max_x = coordinates.iter().map(|p| p.x).max().unwrap().clone();
-    let max_y = coordinates.iter().map(|p| p.y).max().unwrap().clone();
+fn get_safe_region_size(coordinates: &[Point], max_farness: i32) -> usize {
+    let min_x = coordinates.iter().map(|p| p.x).min().unwrap();
+    let min_y = coordinates.iter().map(|p| p.y).min().unwrap();
+    let max_x = coordinates.iter().map(|p| p.x).max().unwrap();
+    let max_y = coordinates.iter().map(|p| p.y).max().unwrap();
     let mut region_size = 0;
 
     for x in min_x..=max_x {
@@ -145,37 +107,34 @@ fn get_safe_region_size(coordinates: &[Point], max_farness: usize) -> usize {
     region_size
 }
 
-fn part2(coordinates: &[Point]) {
-    let region_size = get_safe_region_size(coordinates, 10000);
+pub fn part1(input: String, _config: &Config) -> Output {
+    let points = get_input_points(&input);
+    let area = get_largest_finite_area(&points);
 
-    println!("Part2: {:?}", region_size);
+    Output::Num(area as i64)
 }
 
-fn main() -> io::Result<()> {
-    let os_args: Vec<_> = std::env::args().collect();
-    let lines = get_input_lines(&os_args[1])?;
-    let points = get_input_points(&lines);
-
-    part1(&points);
-    part2(&points);
+pub fn part2(input: String, _config: &Config) -> Output {
+    let points = get_input_points(&input);
+    let region_size = get_safe_region_size(&points, 10000);
 
-    Ok(())
+    Output::Num(region_size as i64)
 }
 
 fn is_in_west_quadrant(origin: &Point, tested: &Point) -> bool {
-    tested.x < origin.x && abs_diff(tested.y, origin.y) <= abs_diff(tested.x, origin.x)
+    tested.x < origin.x && (tested.y - origin.y).abs() <= (tested.x - origin.x).abs()
 }
 
 fn is_in_east_quadrant(origin: &Point, tested: &Point) -> bool {
-    tested.x > origin.x && abs_diff(tested.y, origin.y) <= abs_diff(tested.x, origin.x)
+    tested.x > origin.x && (tested.y - origin.y).abs() <= (tested.x - origin.x).abs()
 }
 
 fn is_in_north_quadrant(origin: &Point, tested: &Point) -> bool {
-    tested.y < origin.y && abs_diff(tested.x, origin.x) <= abs_diff(tested.y, origin.y)
+    tested.y < origin.y && (tested.x - origin.x).abs() <= (tested.y - origin.y).abs()
 }
 
 fn is_in_south_quadrant(origin: &Point, tested: &Point) -> bool {
-    tested.y > origin.y && abs_diff(tested.x, origin.x) <= abs_diff(tested.y, origin.y)
+    tested.y > origin.y && (tested.x - origin.x).abs() <= (tested.y - origin.y).abs()
 }
 
 #[cfg(test)]
@@ -0,0 +1,54 @@
+mod model;
+
+use self::model::*;
+
+use crate::{Config, Output};
+
+fn get_requirements(input: &str) -> Result<Vec<Requirement>, ()> {
+    input.lines().map(|line| line.parse()).collect()
+}
+
+pub fn part1(input: String, _config: &Config) -> Output {
+    let requirements = get_requirements(&input).unwrap();
+    let instructions = Instructions::new(requirements);
+    let answer: String = instructions.steps().collect();
+
+    Output::Str(answer)
+}
+
+pub fn part2(input: String, config: &Config) -> Output {
+    let requirements = get_requirements(&input).unwrap();
+    let instructions = Instructions::new(requirements);
+    let mut team = Team::with_config(config.workers, config.base_cost);
+    let schedule = team.complete_steps(instructions.steps());
+
+    Output::Str(render_schedule(&schedule))
+}
+
+/// Renders the per-second schedule as a column-per-worker table, so the
+/// assignment can be eyeballed instead of just trusting the final time.
+fn render_schedule(schedule: &Schedule) -> String {
+    let worker_count = schedule.ticks.first().map_or(0, |tick| tick.workers.len());
+    let mut table = String::new();
+
+    table.push_str("Second");
+    for w in 0..worker_count {
+        table.push_str(&format!("  W{}", w));
+    }
+    table.push_str("  Done\n");
+
+    for tick in &schedule.ticks {
+        table.push_str(&format!("{:>6}", tick.second));
+
+        for slot in &tick.workers {
+            table.push_str(&format!("   {}", slot.unwrap_or('.')));
+        }
+
+        let done: String = tick.completed.iter().collect();
+        table.push_str(&format!("   {}\n", done));
+    }
+
+    table.push_str(&format!("Total time: {}\n", schedule.total_time));
+
+    table
+}
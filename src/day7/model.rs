@@ -0,0 +1,396 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::str::FromStr;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct Requirement {
+    must_be_finished: char,
+    can_begin: char,
+}
+
+/// Topological ordering of the puzzle steps via Kahn's algorithm: a
+/// `succ` adjacency map plus an `indegree` count per step, with a
+/// min-heap of the steps that are currently ready (indegree zero) so the
+/// lexicographically smallest one is always picked next.
+pub struct Steps {
+    succ: HashMap<char, Vec<char>>,
+    indegree: HashMap<char, usize>,
+    ready: BinaryHeap<Reverse<char>>,
+    total: usize,
+    completed: usize,
+}
+
+impl Steps {
+    pub fn new(reqs: Vec<Requirement>) -> Steps {
+        let mut succ: HashMap<char, Vec<char>> = HashMap::new();
+        let mut indegree: HashMap<char, usize> = HashMap::new();
+
+        for req in &reqs {
+            succ.entry(req.must_be_finished)
+                .or_default()
+                .push(req.can_begin);
+
+            indegree
+                .entry(req.can_begin)
+                .and_modify(|d| *d += 1)
+                .or_insert(1);
+            indegree.entry(req.must_be_finished).or_insert(0);
+        }
+
+        let ready = indegree
+            .iter()
+            .filter(|(_, &degree)| degree == 0)
+            .map(|(&step, _)| Reverse(step))
+            .collect();
+
+        let total = indegree.len();
+
+        Steps {
+            succ,
+            indegree,
+            ready,
+            total,
+            completed: 0,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.completed == self.total
+    }
+
+    /// Pops the lexicographically smallest ready step, if any, without
+    /// marking it as finished.
+    pub fn pop_ready(&mut self) -> Option<char> {
+        self.ready.pop().map(|Reverse(step)| step)
+    }
+
+    /// Marks `step` as finished, decrementing its successors' indegree
+    /// and making any that reach zero ready. Each step can only become
+    /// ready once, since its indegree only ever crosses zero a single
+    /// time.
+    pub fn release(&mut self, step: char) {
+        self.completed += 1;
+
+        if let Some(successors) = self.succ.get(&step) {
+            for &next in successors {
+                if let Some(degree) = self.indegree.get_mut(&next) {
+                    *degree -= 1;
+
+                    if *degree == 0 {
+                        self.ready.push(Reverse(next));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Iterator for Steps {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let step = self.pop_ready()?;
+        self.release(step);
+
+        Some(step)
+    }
+}
+
+pub struct Instructions {
+    requirements: Vec<Requirement>,
+}
+
+impl Instructions {
+    pub fn new(reqs: Vec<Requirement>) -> Instructions {
+        Instructions { requirements: reqs }
+    }
+
+    pub fn steps(&self) -> Steps {
+        Steps::new(self.requirements.clone())
+    }
+}
+
+impl Requirement {
+    pub fn new(mbf: char, cb: char) -> Requirement {
+        Requirement {
+            must_be_finished: mbf,
+            can_begin: cb,
+        }
+    }
+}
+
+fn parse_step_letter(text: &str) -> Option<char> {
+    text.chars().next()
+}
+
+named!(parse_requirement<&str, Requirement>,
+    do_parse!(
+        tag!("Step ") >>
+        mbf :  map_opt!(take_until_and_consume!(" "), parse_step_letter) >>
+        tag!("must be finished before step ") >>
+        c_b :  map_opt!(take_until_and_consume!(" "), parse_step_letter) >>
+        tag!("can begin.") >>
+        (Requirement::new(mbf, c_b))
+    )
+);
+
+impl FromStr for Requirement {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match parse_requirement(s) {
+            Ok((_, req)) => Ok(req),
+            Err(_) => Err(()),
+        }
+    }
+}
+
+pub fn get_step_duration(base_cost: usize, step: char) -> usize {
+    let step_index = step as usize;
+    let first_index = 'A' as usize;
+
+    base_cost + 1 + (step_index - first_index)
+}
+
+#[derive(Debug, Clone)]
+struct Worker {
+    step: Option<char>,
+    remaining: usize,
+}
+
+impl Worker {
+    fn new() -> Worker {
+        Worker {
+            step: None,
+            remaining: 0,
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        self.step.is_none()
+    }
+
+    fn current_step(&self) -> Option<char> {
+        self.step
+    }
+
+    fn assign(&mut self, step: char, time: usize) {
+        self.step = Some(step);
+        self.remaining = time;
+    }
+
+    fn work(&mut self) -> Option<char> {
+        if self.remaining == 0 {
+            None
+        } else if self.remaining == 1 {
+            self.remaining = 0;
+
+            self.step.take()
+        } else {
+            self.remaining -= 1;
+
+            None
+        }
+    }
+}
+
+/// A snapshot of one second of the schedule: what each worker was doing,
+/// and which steps finished during that second.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick {
+    pub second: usize,
+    pub workers: Vec<Option<char>>,
+    pub completed: Vec<char>,
+}
+
+/// The full per-second trace produced by `Team::complete_steps`, so
+/// callers can inspect how the work was spread across workers rather
+/// than just the final elapsed time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schedule {
+    pub ticks: Vec<Tick>,
+    pub total_time: usize,
+}
+
+pub struct Team {
+    workers: Vec<Worker>,
+    base_cost: usize,
+}
+
+impl Team {
+    pub fn with_config(worker_count: usize, base_cost: usize) -> Team {
+        Team {
+            workers: vec![Worker::new(); worker_count],
+            base_cost,
+        }
+    }
+
+    fn release_finished(&mut self, steps: &mut Steps) -> Vec<char> {
+        let mut completed = Vec::new();
+
+        for worker in self.workers.iter_mut() {
+            if let Some(done) = worker.work() {
+                steps.release(done);
+                completed.push(done);
+            }
+        }
+
+        completed
+    }
+
+    fn assign_work(&mut self, steps: &mut Steps) {
+        loop {
+            if !self.workers.iter().any(Worker::is_idle) {
+                break;
+            }
+
+            match steps.pop_ready() {
+                Some(step) => {
+                    let worker = self.workers.iter_mut().find(|w| w.is_idle()).unwrap();
+                    let time = get_step_duration(self.base_cost, step);
+
+                    worker.assign(step, time);
+                }
+                None => break,
+            }
+        }
+    }
+
+    pub fn complete_steps(&mut self, mut steps: Steps) -> Schedule {
+        let mut clock = 0;
+        let mut ticks = Vec::new();
+
+        loop {
+            let completed = self.release_finished(&mut steps);
+            let done = steps.is_complete();
+
+            if !done {
+                self.assign_work(&mut steps);
+            }
+
+            ticks.push(Tick {
+                second: clock,
+                workers: self.workers.iter().map(Worker::current_step).collect(),
+                completed,
+            });
+
+            if done {
+                return Schedule {
+                    ticks,
+                    total_time: clock,
+                };
+            }
+
+            clock += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_parse_req() {
+        let parsed = parse_requirement("Step F must be finished before step E can begin.");
+        let requirement = Requirement::new('F', 'E');
+        let expected = Ok(("", requirement));
+
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn pop_ready_finds_root_step() {
+        let req = Requirement::new('A', 'B');
+        let reqs = vec![req];
+        let mut steps = Steps::new(reqs);
+
+        assert_eq!(Some('A'), steps.pop_ready());
+        assert_eq!(None, steps.pop_ready());
+    }
+
+    #[test]
+    fn release_unlocks_successor() {
+        let req = Requirement::new('A', 'B');
+        let reqs = vec![req];
+        let mut steps = Steps::new(reqs);
+
+        let step = steps.pop_ready().unwrap();
+        steps.release(step);
+
+        assert_eq!(Some('B'), steps.pop_ready());
+    }
+
+    #[test]
+    fn ready_heap_picks_smallest_step_first() {
+        let reqs = vec![Requirement::new('A', 'Z'), Requirement::new('B', 'Z')];
+        let mut steps = Steps::new(reqs);
+
+        let first = steps.pop_ready().unwrap();
+
+        assert_eq!('A', first);
+        assert_eq!(Some('B'), steps.pop_ready());
+    }
+
+    #[test]
+    fn is_complete_tracks_every_step_including_leaves() {
+        let reqs = vec![Requirement::new('A', 'B')];
+        let mut steps = Steps::new(reqs);
+
+        assert_eq!(false, steps.is_complete());
+
+        let step = steps.pop_ready().unwrap();
+        steps.release(step);
+        assert_eq!(false, steps.is_complete());
+
+        let step = steps.pop_ready().unwrap();
+        steps.release(step);
+        assert_eq!(true, steps.is_complete());
+    }
+
+    #[test]
+    fn get_step_duration_matches() {
+        assert_eq!(1, get_step_duration(0, 'A'));
+        assert_eq!(2, get_step_duration(0, 'B'));
+        assert_eq!(3, get_step_duration(0, 'C'));
+        assert_eq!(26, get_step_duration(0, 'Z'));
+        assert_eq!(61, get_step_duration(60, 'A'));
+    }
+
+    #[test]
+    fn team_complete_simplest_instructions() {
+        let reqs = vec![Requirement::new('A', 'B')];
+        let steps = Steps::new(reqs);
+        let mut team = Team::with_config(1, 0);
+        assert_eq!(3, team.complete_steps(steps).total_time);
+    }
+
+    #[test]
+    fn team_complete_matches() {
+        let reqs = vec![
+            Requirement::new('C', 'A'),
+            Requirement::new('C', 'F'),
+            Requirement::new('A', 'B'),
+            Requirement::new('A', 'D'),
+            Requirement::new('B', 'E'),
+            Requirement::new('D', 'E'),
+            Requirement::new('F', 'E'),
+        ];
+        let steps = Steps::new(reqs);
+        let mut team = Team::with_config(2, 0);
+        assert_eq!(15, team.complete_steps(steps).total_time);
+    }
+
+    #[test]
+    fn team_complete_records_a_tick_per_second() {
+        let reqs = vec![Requirement::new('A', 'B')];
+        let steps = Steps::new(reqs);
+        let mut team = Team::with_config(1, 0);
+        let schedule = team.complete_steps(steps);
+
+        assert_eq!(3, schedule.total_time);
+        assert_eq!(4, schedule.ticks.len());
+        assert_eq!(vec!['A'], schedule.ticks[1].completed);
+        assert_eq!(vec!['B'], schedule.ticks[3].completed);
+    }
+}
@@ -0,0 +1,99 @@
+use std::ops::{Add, Sub};
+
+/// A signed 2D coordinate shared by the grid-based puzzles.
+#[derive(Debug, Default, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    pub fn dot(self, other: Point) -> i32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn abs(self) -> Point {
+        Point::new(self.x.abs(), self.y.abs())
+    }
+
+    pub fn signum(self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn manhattan(self, other: Point) -> i32 {
+        (self - other).abs().x + (self - other).abs().y
+    }
+
+    pub fn chebyshev(self, other: Point) -> i32 {
+        let diff = (self - other).abs();
+
+        diff.x.max(diff.y)
+    }
+
+    pub fn clamp(self, min: Point, max: Point) -> Point {
+        Point::new(
+            self.x.max(min.x).min(max.x),
+            self.y.max(min.y).min(max.y),
+        )
+    }
+
+    pub fn transform<F: Fn(i32) -> i32>(self, f: F) -> Point {
+        Point::new(f(self.x), f(self.y))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn manhattan_matches() {
+        let a = Point::new(1, 1);
+        let b = Point::new(4, 5);
+
+        assert_eq!(7, a.manhattan(b));
+    }
+
+    #[test]
+    fn chebyshev_matches() {
+        let a = Point::new(1, 1);
+        let b = Point::new(4, 5);
+
+        assert_eq!(4, a.chebyshev(b));
+    }
+
+    #[test]
+    fn add_and_sub_are_inverse() {
+        let a = Point::new(2, -3);
+        let b = Point::new(5, 7);
+
+        assert_eq!(a, a + b - b);
+    }
+
+    #[test]
+    fn clamp_restricts_to_bounds() {
+        let p = Point::new(-5, 12);
+
+        assert_eq!(Point::new(0, 10), p.clamp(Point::new(0, 0), Point::new(10, 10)));
+    }
+}
@@ -0,0 +1,123 @@
+use std::env;
+use std::fs;
+use std::io::{self, Error, ErrorKind};
+use std::path::PathBuf;
+
+const BASE_URL: &str = "https://adventofcode.com/2018/day";
+
+/// Loads the input for `day`, preferring a cached copy under `inputs/` and
+/// falling back to downloading it from the puzzle site.
+///
+/// When `small` is true, the "input" is instead the first example block
+/// pulled out of the puzzle description, cached separately so it doesn't
+/// clobber the real input.
+pub fn load_input(day: u32, small: bool) -> io::Result<String> {
+    let path = cache_path(day, small);
+
+    if let Ok(cached) = fs::read_to_string(&path) {
+        return Ok(cached);
+    }
+
+    let fetched = if small {
+        fetch_example(day)?
+    } else {
+        fetch_puzzle_input(day)?
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, &fetched)?;
+
+    Ok(fetched)
+}
+
+fn cache_path(day: u32, small: bool) -> PathBuf {
+    let filename = if small {
+        format!("{}.small.txt", day)
+    } else {
+        format!("{}.txt", day)
+    };
+
+    PathBuf::from("inputs").join(filename)
+}
+
+fn session_cookie() -> io::Result<String> {
+    env::var("AOC_COOKIE")
+        .map_err(|_| Error::new(ErrorKind::NotFound, "AOC_COOKIE environment variable is not set"))
+}
+
+fn fetch_puzzle_input(day: u32) -> io::Result<String> {
+    let url = format!("{}/{}/input", BASE_URL, day);
+    let body = get_with_session(&url)?;
+
+    Ok(body.trim_end_matches('\n').to_string())
+}
+
+fn fetch_example(day: u32) -> io::Result<String> {
+    let url = format!("{}/{}", BASE_URL, day);
+    let html = get_with_session(&url)?;
+
+    extract_first_example(&html)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "no example block found in puzzle page"))
+}
+
+fn get_with_session(url: &str) -> io::Result<String> {
+    let cookie = session_cookie()?;
+
+    reqwest::blocking::Client::new()
+        .get(url)
+        .header(reqwest::header::COOKIE, format!("session={}", cookie))
+        .send()
+        .and_then(|response| response.text())
+        .map_err(Error::other)
+}
+
+/// Finds the `<pre><code>` block that follows the first "For example"
+/// paragraph in the puzzle page and returns its unescaped text.
+fn extract_first_example(html: &str) -> Option<String> {
+    let marker = html.find("For example")?;
+    let after_marker = &html[marker..];
+
+    let code_start = after_marker.find("<pre><code>")? + "<pre><code>".len();
+    let code_end = after_marker[code_start..].find("</code></pre>")?;
+
+    Some(unescape_html(&after_marker[code_start..code_start + code_end]))
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn extract_first_example_finds_code_block() {
+        let html = "<p>intro</p><p>For example:</p><pre><code>1, 2\n3, 4</code></pre><p>more</p>";
+
+        assert_eq!(Some("1, 2\n3, 4".to_string()), extract_first_example(html));
+    }
+
+    #[test]
+    fn extract_first_example_unescapes_entities() {
+        let html = "For example: <pre><code>a &amp;&amp; b &lt;&gt; c</code></pre>";
+
+        assert_eq!(
+            Some("a && b <> c".to_string()),
+            extract_first_example(html)
+        );
+    }
+
+    #[test]
+    fn extract_first_example_returns_none_without_marker() {
+        let html = "<pre><code>1, 2</code></pre>";
+
+        assert_eq!(None, extract_first_example(html));
+    }
+}
@@ -0,0 +1,79 @@
+#[macro_use]
+extern crate nom;
+
+pub mod day6;
+pub mod day7;
+pub mod geometry;
+pub mod io;
+
+use std::fmt;
+
+/// The result of running one puzzle part, loosely typed so the runner
+/// can print it without every day having to agree on a single numeric type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Output {
+    Num(i64),
+    Str(String),
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Output::Num(n) => write!(f, "{}", n),
+            Output::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Run-time knobs a day's solution may read, threaded down from the
+/// runner's CLI flags. Days that don't need them simply ignore it.
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub workers: usize,
+    pub base_cost: usize,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            workers: 5,
+            base_cost: 60,
+        }
+    }
+}
+
+pub type Part = fn(String, &Config) -> Output;
+
+fn not_implemented(_input: String, _config: &Config) -> Output {
+    panic!("no solution registered for this day/part yet")
+}
+
+/// Indexed `[day - 1][part - 1]`, so days without a solution yet are
+/// filled with a placeholder that panics if ever invoked.
+pub static SOLUTIONS: [[Part; 2]; 25] = [
+    [not_implemented, not_implemented], // day 1
+    [not_implemented, not_implemented], // day 2
+    [not_implemented, not_implemented], // day 3
+    [not_implemented, not_implemented], // day 4
+    [not_implemented, not_implemented], // day 5
+    [day6::part1, day6::part2],
+    [day7::part1, day7::part2],
+    [not_implemented, not_implemented], // day 8
+    [not_implemented, not_implemented], // day 9
+    [not_implemented, not_implemented], // day 10
+    [not_implemented, not_implemented], // day 11
+    [not_implemented, not_implemented], // day 12
+    [not_implemented, not_implemented], // day 13
+    [not_implemented, not_implemented], // day 14
+    [not_implemented, not_implemented], // day 15
+    [not_implemented, not_implemented], // day 16
+    [not_implemented, not_implemented], // day 17
+    [not_implemented, not_implemented], // day 18
+    [not_implemented, not_implemented], // day 19
+    [not_implemented, not_implemented], // day 20
+    [not_implemented, not_implemented], // day 21
+    [not_implemented, not_implemented], // day 22
+    [not_implemented, not_implemented], // day 23
+    [not_implemented, not_implemented], // day 24
+    [not_implemented, not_implemented], // day 25
+];
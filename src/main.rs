@@ -0,0 +1,63 @@
+use std::env;
+use std::io;
+use std::process;
+
+use adventofcode2018::io as puzzle_io;
+use adventofcode2018::{Config, SOLUTIONS};
+
+struct Args {
+    day: usize,
+    part: usize,
+    small: bool,
+    config: Config,
+}
+
+fn parse_args() -> Args {
+    let mut day = None;
+    let mut part = None;
+    let mut small = false;
+    let mut config = Config::default();
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = args.next().and_then(|v| v.parse().ok()),
+            "--part" => part = args.next().and_then(|v| v.parse().ok()),
+            "--small" => small = true,
+            "--workers" => {
+                config.workers = args
+                    .next()
+                    .and_then(|v| v.parse().ok())
+                    .expect("--workers <N> requires a number")
+            }
+            "--base-cost" => {
+                config.base_cost = args
+                    .next()
+                    .and_then(|v| v.parse().ok())
+                    .expect("--base-cost <N> requires a number")
+            }
+            other => {
+                eprintln!("unrecognized argument: {}", other);
+                process::exit(1);
+            }
+        }
+    }
+
+    Args {
+        day: day.expect("--day <N> is required"),
+        part: part.expect("--part <P> is required"),
+        small,
+        config,
+    }
+}
+
+fn main() -> io::Result<()> {
+    let args = parse_args();
+    let input = puzzle_io::load_input(args.day as u32, args.small)?;
+    let part = SOLUTIONS[args.day - 1][args.part - 1];
+    let output = part(input, &args.config);
+
+    println!("Day {} Part{}: {}", args.day, args.part, output);
+
+    Ok(())
+}